@@ -0,0 +1,223 @@
+/// Rotating operation log for `Update`/`Upgrade` runs
+///
+/// `Update` and `Upgrade` mutate files under `get_prusa_dir()` and pull from a
+/// remote `Repository`, but until now there was no audit trail of what ran and
+/// what it touched. `LogFile` appends one structured entry per run and rotates
+/// the file once it grows past a configured size, so the log itself never
+/// grows unbounded.
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::config::Repository;
+
+/// Name of the active log file inside the PrusaSlicer config dir
+const LOG_FILE_NAME: &str = "wizard.log";
+
+/// A single recorded run of `Update` or `Upgrade`
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: u64,
+    pub command: String,
+    pub repo_url: String,
+    pub resolved_commit: Option<String>,
+    pub files: Vec<PathBuf>,
+}
+
+impl LogEntry {
+    /// Builds an entry from the repository state the run used, so the log and
+    /// `Settings` stay consistent with each other
+    pub fn new(timestamp: u64, command: &str, repo: &Repository, files: Vec<PathBuf>) -> Self {
+        LogEntry {
+            timestamp,
+            command: command.to_string(),
+            repo_url: repo.url.clone(),
+            resolved_commit: repo.last_commit.clone(),
+            files,
+        }
+    }
+
+    /// Renders the entry as a single log line
+    fn format(&self) -> String {
+        format!(
+            "{} {} repo={} commit={} files={}\n",
+            self.timestamp,
+            self.command,
+            self.repo_url,
+            self.resolved_commit.as_deref().unwrap_or("none"),
+            self.files
+                .iter()
+                .map(|f| f.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+/// Appends entries to a rotating log file inside a directory (normally the
+/// PrusaSlicer config dir)
+///
+/// When the active log exceeds `max_size` bytes, it's rotated: `wizard.log` ->
+/// `wizard.log.1` -> `wizard.log.2`, discarding anything past `max_files`.
+/// `max_size(None)` disables rotation entirely.
+#[derive(Debug, Clone)]
+pub struct LogFile {
+    dir: PathBuf,
+    max_size: Option<u64>,
+    max_files: u32,
+}
+
+impl LogFile {
+    /// Creates a `LogFile` rooted at `dir` with rotation disabled by default
+    pub fn new(dir: PathBuf) -> Self {
+        LogFile {
+            dir,
+            max_size: None,
+            max_files: 1,
+        }
+    }
+
+    /// Sets the size in bytes past which the active log is rotated, or
+    /// `None` to disable rotation
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::config::Repository;
+    /// # use crate::log::{LogEntry, LogFile};
+    /// # let dir = std::env::temp_dir().join("prusaslicer-config-wizard-doctest");
+    /// # std::fs::create_dir_all(&dir).unwrap();
+    /// let repo = Repository { url: "https://example.test/repo".to_string(), last_updated: None, last_commit: None };
+    /// let entry = LogEntry::new(0, "update", &repo, vec![]);
+    ///
+    /// let log = LogFile::new(dir).max_size(Some(1024)).max_files(3);
+    /// log.append(&entry).unwrap();
+    /// ```
+    pub fn max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets the number of rotated backups to keep, beyond the active log
+    pub fn max_files(mut self, max_files: u32) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    fn active_path(&self) -> PathBuf {
+        self.dir.join(LOG_FILE_NAME)
+    }
+
+    fn backup_path(&self, index: u32) -> PathBuf {
+        self.dir.join(format!("{}.{}", LOG_FILE_NAME, index))
+    }
+
+    /// Rotates the active log if it's grown past `max_size`
+    fn rotate_if_needed(&self) -> std::io::Result<()> {
+        let Some(max_size) = self.max_size else {
+            return Ok(());
+        };
+
+        let active = self.active_path();
+
+        let size = match fs::metadata(&active) {
+            Ok(meta) => meta.len(),
+            Err(_) => return Ok(()),
+        };
+
+        if size <= max_size {
+            return Ok(());
+        }
+
+        // Discard the oldest backup, then shift every remaining one up by one slot
+        let oldest = self.backup_path(self.max_files);
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+
+        for index in (1..self.max_files).rev() {
+            let from = self.backup_path(index);
+            if from.exists() {
+                fs::rename(from, self.backup_path(index + 1))?;
+            }
+        }
+
+        fs::rename(&active, self.backup_path(1))
+    }
+
+    /// Appends `entry` to the active log, rotating first if necessary
+    pub fn append(&self, entry: &LogEntry) -> std::io::Result<()> {
+        self.rotate_if_needed()?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.active_path())?;
+
+        file.write_all(entry.format().as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(command: &str) -> LogEntry {
+        let repo = Repository {
+            url: "https://example.test/repo".to_string(),
+            last_updated: None,
+            last_commit: None,
+        };
+        LogEntry::new(0, command, &repo, vec![])
+    }
+
+    /// Each test gets its own directory under the system temp dir, named after
+    /// the test itself, so parallel test runs don't trample each other's logs
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("prusaslicer-config-wizard-log-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn no_rotation_when_max_size_is_none() {
+        let dir = scratch_dir("no_rotation_when_max_size_is_none");
+        let log = LogFile::new(dir.clone());
+
+        for _ in 0..50 {
+            log.append(&entry("update")).unwrap();
+        }
+
+        assert!(dir.join(LOG_FILE_NAME).exists());
+        assert!(!dir.join(format!("{}.1", LOG_FILE_NAME)).exists());
+    }
+
+    #[test]
+    fn rotates_once_active_log_exceeds_max_size() {
+        let dir = scratch_dir("rotates_once_active_log_exceeds_max_size");
+        let log = LogFile::new(dir.clone()).max_size(Some(1)).max_files(3);
+
+        log.append(&entry("update")).unwrap();
+        assert!(!dir.join(format!("{}.1", LOG_FILE_NAME)).exists());
+
+        // The active log is now past 1 byte, so this append rotates first
+        log.append(&entry("update")).unwrap();
+        assert!(dir.join(format!("{}.1", LOG_FILE_NAME)).exists());
+        assert!(dir.join(LOG_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn discards_backups_past_max_files() {
+        let dir = scratch_dir("discards_backups_past_max_files");
+        let log = LogFile::new(dir.clone()).max_size(Some(1)).max_files(2);
+
+        // Each append after the first rotates, so this pushes three rotations
+        for _ in 0..4 {
+            log.append(&entry("update")).unwrap();
+        }
+
+        assert!(dir.join(format!("{}.1", LOG_FILE_NAME)).exists());
+        assert!(dir.join(format!("{}.2", LOG_FILE_NAME)).exists());
+        assert!(!dir.join(format!("{}.3", LOG_FILE_NAME)).exists());
+    }
+}
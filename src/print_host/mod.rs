@@ -0,0 +1,66 @@
+/// Uploading generated configuration or sliced G-code to a networked printer
+///
+/// Moonraker, PrusaLink and OctoPrint all accept a file upload over HTTP, but
+/// differ in endpoint shape and authentication: OctoPrint and PrusaLink share
+/// an `X-Api-Key`-authenticated multipart `POST /api/files/local`, while
+/// Moonraker uses `POST /server/files/upload` with an optional API key and a
+/// `print` flag to start immediately. `PrintHost` hides that behind one
+/// interface so the wizard can report success or failure per target.
+mod moonraker;
+mod multipart;
+mod octoprint;
+
+use std::path::Path;
+
+pub use moonraker::Moonraker;
+pub use octoprint::OctoPrint;
+
+/// Which print-host backend a printer's network upload target speaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostKind {
+    Moonraker,
+    PrusaLink,
+    OctoPrint,
+}
+
+/// Where and how to reach a printer's network host
+#[derive(Debug, Clone)]
+pub struct HostConfig {
+    pub kind: HostKind,
+    pub url: String,
+    pub api_key: Option<String>,
+}
+
+/// Options controlling a single upload
+#[derive(Debug, Clone, Default)]
+pub struct UploadOptions {
+    /// Start printing immediately after upload, if the backend supports it
+    pub print: bool,
+}
+
+/// Why an upload, or the reachability check before it, failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UploadError {
+    /// The host didn't respond, or refused the connection
+    Unreachable(String),
+
+    /// The host responded but rejected the request
+    Rejected(String),
+}
+
+/// A backend capable of accepting a file upload
+pub trait PrintHost {
+    /// Checks the host is reachable before attempting an upload
+    fn is_reachable(&self) -> Result<(), UploadError>;
+
+    /// Uploads `file` to the host, optionally starting the print
+    fn upload(&self, file: &Path, opts: &UploadOptions) -> Result<(), UploadError>;
+}
+
+/// Builds the `PrintHost` backend matching `config.kind`
+pub fn host_for(config: &HostConfig) -> Box<dyn PrintHost + '_> {
+    match config.kind {
+        HostKind::Moonraker => Box::new(Moonraker::new(config)),
+        HostKind::PrusaLink | HostKind::OctoPrint => Box::new(OctoPrint::new(config)),
+    }
+}
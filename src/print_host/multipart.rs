@@ -0,0 +1,74 @@
+/// Minimal `multipart/form-data` body encoding
+///
+/// Shared by the OctoPrint and Moonraker backends, which both upload a file
+/// alongside a `print` flag as a multipart form rather than a query string or
+/// a bare request body.
+const BOUNDARY: &str = "PrusaSlicerConfigWizardBoundary7f3a9c2b";
+
+/// The `Content-Type` header value to send alongside a body from `build_upload_body`
+pub fn content_type() -> String {
+    format!("multipart/form-data; boundary={}", BOUNDARY)
+}
+
+/// Encodes a file part (field `file`) and a `print` flag part (field `print`)
+/// into a single multipart body, boundary-delimited per RFC 2046
+///
+/// # Example
+///
+/// ```rust
+/// # use crate::print_host::multipart::build_upload_body;
+/// let body = build_upload_body("job.gcode", b"G28\n", true);
+/// let body = String::from_utf8_lossy(&body);
+///
+/// assert!(body.contains("name=\"file\"; filename=\"job.gcode\""));
+/// assert!(body.contains("name=\"print\""));
+/// assert!(body.ends_with("--\r\n"));
+/// ```
+pub fn build_upload_body(file_name: &str, file_bytes: &[u8], print: bool) -> Vec<u8> {
+    let mut body = Vec::new();
+
+    body.extend_from_slice(format!("--{}\r\n", BOUNDARY).as_bytes());
+    body.extend_from_slice(b"Content-Disposition: form-data; name=\"print\"\r\n\r\n");
+    body.extend_from_slice(if print { b"true" } else { b"false" });
+    body.extend_from_slice(b"\r\n");
+
+    body.extend_from_slice(format!("--{}\r\n", BOUNDARY).as_bytes());
+    body.extend_from_slice(
+        format!(
+            "Content-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+            file_name,
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(file_bytes);
+    body.extend_from_slice(b"\r\n");
+
+    body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_both_parts_with_boundaries() {
+        let body = build_upload_body("job.gcode", b"G28\n", true);
+        let body = String::from_utf8(body).unwrap();
+
+        assert!(body.starts_with(&format!("--{}\r\n", BOUNDARY)));
+        assert!(body.contains("name=\"print\"\r\n\r\ntrue\r\n"));
+        assert!(body.contains("name=\"file\"; filename=\"job.gcode\""));
+        assert!(body.contains("G28\n"));
+        assert!(body.ends_with(&format!("--{}--\r\n", BOUNDARY)));
+    }
+
+    #[test]
+    fn print_flag_is_encoded_as_a_form_field_not_a_query_string() {
+        let body = build_upload_body("job.gcode", b"", false);
+        let body = String::from_utf8(body).unwrap();
+
+        assert!(body.contains("name=\"print\"\r\n\r\nfalse\r\n"));
+    }
+}
@@ -0,0 +1,55 @@
+/// OctoPrint and PrusaLink backend
+///
+/// Both speak the same API for our purposes: an `X-Api-Key` header against a
+/// multipart `POST /api/files/local`.
+use std::path::Path;
+
+use super::multipart::{build_upload_body, content_type};
+use super::{HostConfig, PrintHost, UploadError, UploadOptions};
+
+pub struct OctoPrint<'a> {
+    config: &'a HostConfig,
+}
+
+impl<'a> OctoPrint<'a> {
+    pub fn new(config: &'a HostConfig) -> Self {
+        OctoPrint { config }
+    }
+
+    fn files_endpoint(&self) -> String {
+        format!("{}/api/files/local", self.config.url.trim_end_matches('/'))
+    }
+
+    fn request(&self, request: ureq::Request) -> ureq::Request {
+        match &self.config.api_key {
+            Some(key) => request.set("X-Api-Key", key),
+            None => request,
+        }
+    }
+}
+
+impl<'a> PrintHost for OctoPrint<'a> {
+    fn is_reachable(&self) -> Result<(), UploadError> {
+        self.request(ureq::get(&self.config.url))
+            .call()
+            .map(|_| ())
+            .map_err(|e| UploadError::Unreachable(e.to_string()))
+    }
+
+    fn upload(&self, file: &Path, opts: &UploadOptions) -> Result<(), UploadError> {
+        let file_bytes = std::fs::read(file).map_err(|e| UploadError::Rejected(e.to_string()))?;
+
+        let file_name = file
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| UploadError::Rejected("upload path has no file name".to_string()))?;
+
+        let body = build_upload_body(file_name, &file_bytes, opts.print);
+
+        self.request(ureq::post(&self.files_endpoint()))
+            .set("Content-Type", &content_type())
+            .send_bytes(&body)
+            .map(|_| ())
+            .map_err(|e| UploadError::Rejected(e.to_string()))
+    }
+}
@@ -11,13 +11,82 @@ pub struct Property<'a> {
     pub value: Option<Vec<&'a str>>,
 }
 
+impl<'a> Property<'a> {
+    /// Returns the property's elements, or an empty slice if it has no value
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::Property;
+    /// let mut prop = Property { key: "filament_type", value: Some(vec!["PLA"]) };
+    /// prop.push_value("PETG");
+    ///
+    /// assert_eq!(prop.values(), &["PLA", "PETG"]);
+    /// assert_eq!(prop.value_scalar(), Err("property has more than one value"));
+    ///
+    /// prop.remove_value("PLA");
+    /// assert_eq!(prop.value_scalar(), Ok(Some("PETG")));
+    /// ```
+    pub fn values(&self) -> &[&'a str] {
+        self.value.as_deref().unwrap_or(&[])
+    }
+
+    /// Appends `value` as a new element, creating the value if it was `None`
+    pub fn push_value(&mut self, value: &'a str) {
+        self.value.get_or_insert_with(Vec::new).push(value);
+    }
+
+    /// Removes the first element equal to `value`, if present
+    pub fn remove_value(&mut self, value: &str) {
+        if let Some(values) = &mut self.value {
+            if let Some(index) = values.iter().position(|v| *v == value) {
+                values.remove(index);
+            }
+        }
+    }
+
+    /// Replaces the property's elements, preserving the given order on write
+    pub fn set_values(&mut self, values: Vec<&'a str>) {
+        self.value = Some(values);
+    }
+
+    /// Returns the property's single element, erroring if it holds more than one
+    pub fn value_scalar(&self) -> Result<Option<&'a str>, &'static str> {
+        match self.value.as_deref() {
+            None => Ok(None),
+            Some([]) => Ok(None),
+            Some([single]) => Ok(Some(single)),
+            Some(_) => Err("property has more than one value"),
+        }
+    }
+}
+
 /// Represents a PrusaSlicer configuration section within a file
+///
+/// A section header like `[printer:Original Prusa i3 MK3]` encodes a category
+/// (`type_name`) and an instance name (`id`) separated by `:`. Headers with no
+/// colon, like `[presets]`, are type-only with an empty `id`. `name` always
+/// keeps the original raw header so writing stays lossless.
 #[derive(Debug, Clone)]
 pub struct Section<'a> {
     pub name: &'a str,
+    pub type_name: &'a str,
+    pub id: &'a str,
     pub properties: Vec<Property<'a>>,
 }
 
+impl<'a> Section<'a> {
+    /// Builds a section header, splitting `name` into `type_name` and `id` on
+    /// the first `:`, if present
+    fn new(name: &'a str, properties: Vec<Property<'a>>) -> Self {
+        let (type_name, id) = match name.split_once(':') {
+            Some((type_name, id)) => (type_name, id),
+            None => (name, ""),
+        };
+
+        Section { name, type_name, id, properties }
+    }
+}
+
 /// Represents a PrusaSlicer configuration file
 /// Can be converted to a ConfigMap
 #[derive(Debug, Clone)]
@@ -54,10 +123,7 @@ impl<'a> ConfigFile<'a> {
         let mut in_section = false;
 
         // Metadata about the current section
-        let mut section = Section {
-            name: "",
-            properties: Vec::new(),
-        };
+        let mut section = Section::new("", Vec::new());
 
         // Parse parsed lines
         parser.for_each(|line| {
@@ -74,10 +140,7 @@ impl<'a> ConfigFile<'a> {
 
                 Item::Section(name) => {
                     // Start off new section
-                    section = Section {
-                        name,
-                        properties: Vec::new(),
-                    };
+                    section = Section::new(name, Vec::new());
 
                     in_section = true;
                 }
@@ -107,6 +170,36 @@ impl<'a> ConfigFile<'a> {
         Ok(file)
     }
 
+    /// Iterates over every section whose `type_name` matches `type_name`, e.g.
+    /// `sections_of_type("printer")` for every `[printer:...]` section
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::ConfigFile;
+    /// let file = ConfigFile::parse("[printer:MK3]\n\n[printer:MK4]\n\n[print:0.20mm]\n").unwrap();
+    ///
+    /// let ids: Vec<&str> = file.sections_of_type("printer").map(|s| s.id).collect();
+    /// assert_eq!(ids, vec!["MK3", "MK4"]);
+    /// ```
+    pub fn sections_of_type<'b>(&'b self, type_name: &'b str) -> impl Iterator<Item = &'b Section<'a>> {
+        self.sections.iter().filter(move |s| s.type_name == type_name)
+    }
+
+    /// Looks up a single section by its `type_name` and `id`, e.g.
+    /// `section("printer", "MK3")` for `[printer:MK3]`
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::ConfigFile;
+    /// let file = ConfigFile::parse("[printer:MK3]\nnozzle_diameter = 0.4\n").unwrap();
+    ///
+    /// assert!(file.section("printer", "MK3").is_some());
+    /// assert!(file.section("printer", "MK4").is_none());
+    /// ```
+    pub fn section(&self, type_name: &str, id: &str) -> Option<&Section<'a>> {
+        self.sections.iter().find(|s| s.type_name == type_name && s.id == id)
+    }
+
     /// Converts a ConfigFile to a ConfigMap
     pub fn to_map(&self) -> ConfigMap {
         // Create new ConfigMap
@@ -234,6 +327,81 @@ impl<'a> ConfigFile<'a> {
     }
 }
 
+impl<'a> ConfigMap<'a> {
+    /// Looks up the raw value slot for `(section, key)`, where an empty
+    /// `section` means a global property
+    fn value_mut(&mut self, section: &str, key: &str) -> Option<&mut Option<Vec<&'a str>>> {
+        if section.is_empty() {
+            self.properties.get_mut(key)
+        } else {
+            self.sections.get_mut(section)?.get_mut(key)
+        }
+    }
+
+    /// Returns the elements of `(section, key)`, or an empty slice if unset
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::ConfigFile;
+    /// let mut map = ConfigFile::parse("[printer:MK3]\nnozzle_diameter = 0.4\n").unwrap().to_map();
+    /// map.push_value("printer:MK3", "nozzle_diameter", "0.6");
+    ///
+    /// assert_eq!(map.values("printer:MK3", "nozzle_diameter"), &["0.4", "0.6"]);
+    /// assert_eq!(map.value_scalar("printer:MK3", "nozzle_diameter"), Err("property has more than one value"));
+    ///
+    /// map.remove_value("printer:MK3", "nozzle_diameter", "0.4");
+    /// assert_eq!(map.value_scalar("printer:MK3", "nozzle_diameter"), Ok(Some("0.6")));
+    /// ```
+    pub fn values(&self, section: &str, key: &str) -> &[&'a str] {
+        let value = if section.is_empty() {
+            self.properties.get(key)
+        } else {
+            self.sections.get(section).and_then(|s| s.get(key))
+        };
+
+        value.and_then(|v| v.as_deref()).unwrap_or(&[])
+    }
+
+    /// Appends `value` to `(section, key)`, creating it if missing
+    pub fn push_value(&mut self, section: &'a str, key: &'a str, value: &'a str) {
+        let slot = if section.is_empty() {
+            self.properties.entry(key).or_insert(None)
+        } else {
+            self.sections.entry(section).or_default().entry(key).or_insert(None)
+        };
+
+        slot.get_or_insert_with(Vec::new).push(value);
+    }
+
+    /// Removes the first element equal to `value` from `(section, key)`, if present
+    pub fn remove_value(&mut self, section: &str, key: &str, value: &str) {
+        if let Some(Some(values)) = self.value_mut(section, key) {
+            if let Some(index) = values.iter().position(|v| *v == value) {
+                values.remove(index);
+            }
+        }
+    }
+
+    /// Replaces the elements of `(section, key)`, preserving order on write
+    pub fn set_values(&mut self, section: &'a str, key: &'a str, values: Vec<&'a str>) {
+        if section.is_empty() {
+            self.properties.insert(key, Some(values));
+        } else {
+            self.sections.entry(section).or_default().insert(key, Some(values));
+        }
+    }
+
+    /// Returns the single element of `(section, key)`, erroring if it holds
+    /// more than one
+    pub fn value_scalar(&self, section: &str, key: &str) -> Result<Option<&'a str>, &'static str> {
+        match self.values(section, key) {
+            [] => Ok(None),
+            [single] => Ok(Some(single)),
+            _ => Err("property has more than one value"),
+        }
+    }
+}
+
 impl ConfigMap<'_> {
     /// Converts a grop of ConfigMap properties to a group of ConfigFile properties
     /// Loops over each hashmap entry and converts it to a ConfigFile property by taking the has
@@ -307,7 +475,7 @@ impl ConfigMap<'_> {
     ) -> Section<'a> {
         let properties = Self::properties_to_file(section);
 
-        Section { name, properties }
+        Section::new(name, properties)
     }
 
     /// Converts a ConfigMap to a ConfigFile
@@ -373,3 +541,192 @@ impl ConfigMap<'_> {
         file
     }
 }
+
+/// Where a configuration layer in a `ConfigSet` came from
+///
+/// Used to report to the user, e.g., whether a value is "overridden locally" or
+/// "from upstream repo" when showing diffs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    /// Bundled default, used when no repo or local override supplies a value
+    Default,
+
+    /// Pulled from the given repository url
+    Repo(String),
+
+    /// Overridden by the user in a local file at the given path
+    LocalOverride(String),
+}
+
+/// A single layer within a `ConfigSet`, tagged with where it came from
+#[derive(Debug, Clone)]
+pub struct Layer<'a> {
+    pub origin: Origin,
+    pub map: ConfigMap<'a>,
+}
+
+/// An ordered stack of configuration layers
+///
+/// `ConfigSet` models configuration pulled from several sources at once, e.g. a
+/// git `Repository` and a user's local overrides. Layers are stored lowest to
+/// highest priority; resolving the set walks them in order so the
+/// highest-priority layer supplying a key wins, while recording which origin
+/// supplied each resolved `(section, key)` pair. This keeps upstream and local
+/// changes separable so an `Upgrade` can re-merge after a repo update without
+/// clobbering user edits.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSet<'a> {
+    pub layers: Vec<Layer<'a>>,
+}
+
+impl<'a> ConfigSet<'a> {
+    /// Creates an empty `ConfigSet` with no layers
+    pub fn new() -> Self {
+        ConfigSet { layers: Vec::new() }
+    }
+
+    /// Appends a layer, which takes priority over every layer already present
+    pub fn push(&mut self, origin: Origin, map: ConfigMap<'a>) {
+        self.layers.push(Layer { origin, map });
+    }
+
+    /// Merges all layers, returning the flattened map alongside the origin
+    /// that supplied each resolved `(section, key)` pair
+    ///
+    /// A later (higher-priority) layer overwrites an earlier one for the same
+    /// key, including when the later value is empty, since a present-but-empty
+    /// value still means "set" rather than "absent".
+    fn resolve(&self) -> (ConfigMap<'a>, HashMap<(String, String), Origin>) {
+        let mut map = ConfigMap {
+            properties: HashMap::new(),
+            sections: HashMap::new(),
+        };
+        let mut origins = HashMap::new();
+
+        for layer in self.layers.iter() {
+            for (key, value) in layer.map.properties.iter() {
+                map.properties.insert(key, value.clone());
+                origins.insert((String::new(), key.to_string()), layer.origin.clone());
+            }
+
+            for (section, props) in layer.map.sections.iter() {
+                let entry = map.sections.entry(section).or_insert_with(HashMap::new);
+
+                for (key, value) in props.iter() {
+                    entry.insert(key, value.clone());
+                    origins.insert((section.to_string(), key.to_string()), layer.origin.clone());
+                }
+            }
+        }
+
+        (map, origins)
+    }
+
+    /// Looks up the resolved value and origin for a single `(section, key)` pair
+    ///
+    /// `section` may be empty to look up a global (top-level) property.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::{ConfigFile, ConfigSet, Origin};
+    /// let default_map = ConfigFile::parse("[print:0.20mm]\nlayer_height = 0.2\n").unwrap().to_map();
+    /// let repo_map = ConfigFile::parse("[print:0.20mm]\nlayer_height = 0.2\n").unwrap().to_map();
+    /// let local_map = ConfigFile::parse("[print:0.20mm]\nlayer_height = 0.15\n").unwrap().to_map();
+    ///
+    /// let mut set = ConfigSet::new();
+    /// set.push(Origin::Default, default_map);
+    /// set.push(Origin::Repo("https://example.com/repo.git".to_string()), repo_map);
+    /// set.push(Origin::LocalOverride("~/.config/wizard/local.ini".to_string()), local_map);
+    ///
+    /// let (value, origin) = set.get("print:0.20mm", "layer_height").unwrap();
+    ///
+    /// assert_eq!(value, vec!["0.15"]);
+    /// assert_eq!(origin, Origin::LocalOverride("~/.config/wizard/local.ini".to_string()));
+    /// ```
+    pub fn get(&self, section: &str, key: &str) -> Option<(Vec<&'a str>, Origin)> {
+        let (map, origins) = self.resolve();
+
+        let value = if section.is_empty() {
+            map.properties.get(key)?
+        } else {
+            map.sections.get(section)?.get(key)?
+        };
+
+        let origin = origins.get(&(section.to_string(), key.to_string()))?.clone();
+
+        Some((value.clone().unwrap_or_default(), origin))
+    }
+
+    /// Flattens every layer into a single `ConfigMap`, discarding origin info
+    pub fn flatten(&self) -> ConfigMap<'a> {
+        self.resolve().0
+    }
+}
+
+#[cfg(test)]
+mod config_set_tests {
+    use super::*;
+
+    fn map_with(section: &'static str, key: &'static str, value: Option<Vec<&'static str>>) -> ConfigMap<'static> {
+        let mut sections = HashMap::new();
+        let mut props = HashMap::new();
+        props.insert(key, value);
+        sections.insert(section, props);
+
+        ConfigMap { properties: HashMap::new(), sections }
+    }
+
+    #[test]
+    fn higher_priority_layer_wins_and_is_recorded_as_origin() {
+        let mut set = ConfigSet::new();
+        set.push(Origin::Default, map_with("print", "layer_height", Some(vec!["0.2"])));
+        set.push(
+            Origin::Repo("https://example.com/repo.git".to_string()),
+            map_with("print", "layer_height", Some(vec!["0.3"])),
+        );
+        set.push(
+            Origin::LocalOverride("local.ini".to_string()),
+            map_with("print", "layer_height", Some(vec!["0.15"])),
+        );
+
+        let (value, origin) = set.get("print", "layer_height").unwrap();
+
+        assert_eq!(value, vec!["0.15"]);
+        assert_eq!(origin, Origin::LocalOverride("local.ini".to_string()));
+    }
+
+    #[test]
+    fn missing_key_falls_through_to_a_lower_layer() {
+        let mut set = ConfigSet::new();
+        set.push(Origin::Default, map_with("print", "layer_height", Some(vec!["0.2"])));
+        set.push(Origin::Repo("https://example.com/repo.git".to_string()), map_with("print", "fill_density", Some(vec!["20%"])));
+
+        let (value, origin) = set.get("print", "layer_height").unwrap();
+
+        assert_eq!(value, vec!["0.2"]);
+        assert_eq!(origin, Origin::Default);
+    }
+
+    #[test]
+    fn present_but_empty_value_still_shadows_a_lower_layer() {
+        let mut set = ConfigSet::new();
+        set.push(Origin::Default, map_with("print", "notes", Some(vec!["hello"])));
+        set.push(Origin::LocalOverride("local.ini".to_string()), map_with("print", "notes", Some(vec![])));
+
+        let (value, origin) = set.get("print", "notes").unwrap();
+
+        assert_eq!(value, Vec::<&str>::new());
+        assert_eq!(origin, Origin::LocalOverride("local.ini".to_string()));
+    }
+
+    #[test]
+    fn flatten_discards_origin_but_keeps_resolved_values() {
+        let mut set = ConfigSet::new();
+        set.push(Origin::Default, map_with("print", "layer_height", Some(vec!["0.2"])));
+        set.push(Origin::Repo("https://example.com/repo.git".to_string()), map_with("print", "layer_height", Some(vec!["0.3"])));
+
+        let flattened = set.flatten();
+
+        assert_eq!(flattened.sections.get("print").unwrap().get("layer_height"), Some(&Some(vec!["0.3"])));
+    }
+}
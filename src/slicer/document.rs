@@ -0,0 +1,167 @@
+/// A lossless, editable representation of a PrusaSlicer configuration file
+///
+/// `ConfigFile`/`ConfigMap` drop comments and blank lines and reorder sections on
+/// write, which is fine for reading values but mangles a user's hand-annotated
+/// profile if it's read back and rewritten. `Document` instead keeps every
+/// comment, blank line, section header and property as an `Event` and replays
+/// them in their original order on `format`. Properties are written back as
+/// `key = value` and comments as `# text`, so an unchanged file round-trips
+/// byte-for-byte only if it already uses that spacing; other spacing is
+/// normalized. Edits go through `set`/`remove`/`insert`, which touch only the
+/// events for the keys being managed.
+use ini_core::{Item, Parser};
+
+/// A single line of a configuration file, in original order
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<'a> {
+    Comment(&'a str),
+    BlankLine,
+    Section(&'a str),
+    Property(&'a str, Option<Vec<&'a str>>),
+}
+
+/// The full event list for one configuration file
+#[derive(Debug, Clone, Default)]
+pub struct Document<'a> {
+    pub events: Vec<Event<'a>>,
+}
+
+impl<'a> Document<'a> {
+    /// Parses `contents` into an event list, preserving comments, blank lines and order
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::document::Document;
+    /// let mut doc = Document::parse("[print:0.20mm]\n# a comment\nlayer_height = 0.2\n");
+    /// doc.set(Some("print:0.20mm"), "layer_height", Some(vec!["0.3"]));
+    ///
+    /// let mut out = String::new();
+    /// doc.format(&mut out);
+    ///
+    /// assert_eq!(out, "[print:0.20mm]\n# a comment\nlayer_height = 0.3\n");
+    /// ```
+    pub fn parse(contents: &'a str) -> Document<'a> {
+        let parser = Parser::new(contents)
+            .auto_trim(true)
+            .comment_char(b'#');
+
+        let mut doc = Document { events: Vec::new() };
+
+        parser.for_each(|line| {
+            let event = match line {
+                Item::Comment(text) => Some(Event::Comment(text)),
+                Item::Blank => Some(Event::BlankLine),
+                Item::Section(name) => Some(Event::Section(name)),
+                Item::Property(key, value) => {
+                    let value = value.map(|v| v.split(';').collect());
+                    Some(Event::Property(key, value))
+                }
+                _ => None,
+            };
+
+            if let Some(event) = event {
+                doc.events.push(event);
+            }
+        });
+
+        doc
+    }
+
+    /// Returns the index range `[start, end)` for the given section's body,
+    /// i.e. the events between its `Section` header and the next one, or EOF.
+    /// Global properties (before any section) are addressed with `section = None`.
+    /// Returns `None` if `section` is `Some` and no such header exists yet.
+    fn section_range(&self, section: Option<&str>) -> Option<(usize, usize)> {
+        let mut start = 0;
+
+        if let Some(section) = section {
+            let header = self
+                .events
+                .iter()
+                .position(|e| matches!(e, Event::Section(name) if *name == section));
+
+            start = header? + 1;
+        }
+
+        let end = self.events[start..]
+            .iter()
+            .position(|e| matches!(e, Event::Section(_)))
+            .map(|offset| start + offset)
+            .unwrap_or(self.events.len());
+
+        Some((start, end))
+    }
+
+    /// Updates `key` to `value` within `section` (`None` for global properties),
+    /// in place if it already exists, or via `insert` otherwise (which creates
+    /// `section` first if it doesn't exist yet)
+    pub fn set(&mut self, section: Option<&'a str>, key: &'a str, value: Option<Vec<&'a str>>) {
+        let existing = self.section_range(section).and_then(|(start, end)| {
+            self.events[start..end]
+                .iter_mut()
+                .find(|e| matches!(e, Event::Property(k, _) if *k == key))
+        });
+
+        match existing {
+            Some(Event::Property(_, v)) => *v = value,
+            _ => self.insert(section, key, value),
+        }
+    }
+
+    /// Removes `key` from `section` (`None` for global properties) if present;
+    /// a no-op if `section` doesn't exist
+    pub fn remove(&mut self, section: Option<&str>, key: &str) {
+        let Some((start, end)) = self.section_range(section) else {
+            return;
+        };
+
+        if let Some(offset) = self.events[start..end]
+            .iter()
+            .position(|e| matches!(e, Event::Property(k, _) if *k == key))
+        {
+            self.events.remove(start + offset);
+        }
+    }
+
+    /// Inserts a new `key = value` property into `section`, placed immediately
+    /// after that section's last existing property (or right after its header
+    /// if it has none), so it reads as adjacent to the keys the tool manages.
+    /// If `section` doesn't exist yet, its header is appended at EOF first.
+    pub fn insert(&mut self, section: Option<&'a str>, key: &'a str, value: Option<Vec<&'a str>>) {
+        let (start, end) = match self.section_range(section) {
+            Some(range) => range,
+            None => {
+                let name = section.expect("section_range(None) always returns Some");
+                self.events.push(Event::Section(name));
+                (self.events.len(), self.events.len())
+            }
+        };
+
+        let insert_at = self.events[start..end]
+            .iter()
+            .rposition(|e| matches!(e, Event::Property(_, _)))
+            .map(|offset| start + offset + 1)
+            .unwrap_or(start);
+
+        self.events.insert(insert_at, Event::Property(key, value));
+    }
+
+    /// Replays every event back into its original textual form
+    ///
+    /// `auto_trim` strips the leading space `ini_core` treats as part of the
+    /// comment delimiter, so it's added back unconditionally here; a comment
+    /// originally written with no space after `#` does not round-trip exactly.
+    pub fn format(&self, out: &mut String) {
+        for event in self.events.iter() {
+            match event {
+                Event::Comment(text) => out.push_str(&format!("# {}\n", text)),
+                Event::BlankLine => out.push('\n'),
+                Event::Section(name) => out.push_str(&format!("[{}]\n", name)),
+                Event::Property(key, value) => {
+                    let value = value.clone().map(|v| v.join(";")).unwrap_or_default();
+                    out.push_str(&format!("{} = {}\n", key, value));
+                }
+            }
+        }
+    }
+}
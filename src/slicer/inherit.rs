@@ -0,0 +1,155 @@
+/// Resolution of PrusaSlicer `inherits` chains across sections and files
+use std::collections::HashMap;
+
+use super::config::ConfigFile;
+
+/// Problems that can occur while resolving an `inherits` chain
+#[derive(Debug, Clone, PartialEq)]
+pub enum InheritError {
+    /// The named section isn't defined in the file being resolved or any of the extra files
+    SectionNotFound(String),
+
+    /// A section was visited twice while walking `inherits`, meaning the chain cycles
+    Cycle(String),
+}
+
+impl<'a> ConfigFile<'a> {
+    /// Finds a section by its raw name (e.g. a `resolve_inherits` root call) or
+    /// its bare id (e.g. an `inherits = base` parent reference, which never
+    /// carries the `type:` prefix), searching `self` first and then `extra`
+    fn find_section<'b>(&'b self, name: &str, extra: &'b [&'b ConfigFile<'b>]) -> Option<&'b super::config::Section<'b>> {
+        fn matches(section: &super::config::Section, name: &str) -> bool {
+            section.name == name || section.id == name
+        }
+
+        if let Some(section) = self.sections.iter().find(|s| matches(s, name)) {
+            return Some(section);
+        }
+
+        extra.iter().find_map(|file| file.sections.iter().find(|s| matches(s, name)))
+    }
+
+    /// Resolves `section_name`'s `inherits` chain into a fully-materialized set of properties
+    ///
+    /// `inherits` may name several parents separated by `;`; they're resolved
+    /// left-to-right with later parents overriding earlier ones, and the child's
+    /// own properties always win last. Parents are searched in `self` first, then
+    /// in `extra`, which lets a printer preset inherit from a base preset defined
+    /// in a different file. Revisiting a section while walking the chain is
+    /// reported as a cycle instead of recursing forever.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crate::slicer::config::ConfigFile;
+    /// let file = ConfigFile::parse("[print:base]\nlayer_height = 0.2\n\n[print:child]\ninherits = base\nlayer_height = 0.1\n").unwrap();
+    ///
+    /// let resolved = file.resolve_inherits("print:child", &[]).unwrap();
+    ///
+    /// assert_eq!(resolved.get("layer_height"), Some(&Some(vec!["0.1"])));
+    /// ```
+    pub fn resolve_inherits(
+        &'a self,
+        section_name: &str,
+        extra: &'a [&'a ConfigFile<'a>],
+    ) -> Result<HashMap<&'a str, Option<Vec<&'a str>>>, InheritError> {
+        self.resolve_inherits_inner(section_name, extra, &[])
+    }
+
+    /// `visited` holds only the sections on the current path from the root
+    /// being resolved, not every section visited anywhere in the resolution.
+    /// Each parent gets its own copy (via `to_vec`/`push` below) rather than a
+    /// shared `&mut` vec, so two siblings that both transitively inherit from
+    /// the same ancestor (a diamond) don't falsely report a cycle on the
+    /// second one.
+    fn resolve_inherits_inner(
+        &'a self,
+        section_name: &str,
+        extra: &'a [&'a ConfigFile<'a>],
+        visited: &[String],
+    ) -> Result<HashMap<&'a str, Option<Vec<&'a str>>>, InheritError> {
+        let section = self
+            .find_section(section_name, extra)
+            .ok_or_else(|| InheritError::SectionNotFound(section_name.to_string()))?;
+
+        // Track sections by their raw header name, so a root looked up as
+        // `print:a` and a parent reference `a` count as the same section
+        if visited.iter().any(|v| v == section.name) {
+            return Err(InheritError::Cycle(section.name.to_string()));
+        }
+
+        let mut visited = visited.to_vec();
+        visited.push(section.name.to_string());
+
+        let mut resolved = HashMap::new();
+
+        let parents = section
+            .properties
+            .iter()
+            .find(|p| p.key == "inherits")
+            .and_then(|p| p.value.clone())
+            .unwrap_or_default();
+
+        // Resolve parents left-to-right so later parents override earlier ones
+        for parent in parents.iter().filter(|p| !p.is_empty()) {
+            let parent_props = self.resolve_inherits_inner(parent, extra, &visited)?;
+            resolved.extend(parent_props);
+        }
+
+        // Child's own properties always win last
+        for property in section.properties.iter() {
+            if property.key == "inherits" {
+                continue;
+            }
+            resolved.insert(property.key, property.value.clone());
+        }
+
+        Ok(resolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diamond_inheritance_does_not_falsely_report_a_cycle() {
+        let file = ConfigFile::parse(
+            "[print:fdm_process_common]\nfill_pattern = grid\n\n\
+             [print:common]\ninherits = fdm_process_common\nlayer_height = 0.2\n\n\
+             [print:fdm_supports]\ninherits = fdm_process_common\nsupport_material = 1\n\n\
+             [print:quality]\ninherits = common;fdm_supports\n",
+        )
+        .unwrap();
+
+        let resolved = file.resolve_inherits("print:quality", &[]).unwrap();
+
+        assert_eq!(resolved.get("fill_pattern"), Some(&Some(vec!["grid"])));
+        assert_eq!(resolved.get("layer_height"), Some(&Some(vec!["0.2"])));
+        assert_eq!(resolved.get("support_material"), Some(&Some(vec!["1"])));
+    }
+
+    #[test]
+    fn real_cycle_is_still_reported() {
+        let file = ConfigFile::parse(
+            "[print:a]\ninherits = b\n\n[print:b]\ninherits = a\n",
+        )
+        .unwrap();
+
+        let result = file.resolve_inherits("print:a", &[]);
+
+        assert_eq!(result, Err(InheritError::Cycle("print:a".to_string())));
+    }
+
+    #[test]
+    fn child_properties_override_inherited_ones() {
+        let file = ConfigFile::parse(
+            "[print:base]\nlayer_height = 0.2\n\n[print:child]\ninherits = base\nlayer_height = 0.1\n",
+        )
+        .unwrap();
+
+        let resolved = file.resolve_inherits("print:child", &[]).unwrap();
+
+        assert_eq!(resolved.get("layer_height"), Some(&Some(vec!["0.1"])));
+    }
+}
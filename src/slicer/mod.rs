@@ -0,0 +1,5 @@
+/// PrusaSlicer configuration file model: parsing, merging and validation
+pub mod config;
+pub mod document;
+pub mod inherit;
+pub mod schema;
@@ -0,0 +1,153 @@
+/// Typed schemas for PrusaSlicer configuration sections, and validation against them
+use std::collections::HashMap;
+
+use super::config::ConfigFile;
+
+/// The expected shape of a property's value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueKind {
+    Bool,
+    Int,
+    Float,
+    Enum(Vec<&'static str>),
+    FloatList,
+}
+
+/// A single expected property within a `SectionSchema`
+#[derive(Debug, Clone)]
+pub struct PropertySchema {
+    pub key: &'static str,
+    pub required: bool,
+    pub kind: ValueKind,
+}
+
+/// Describes the expected properties of a section type, e.g. `print` or `printer`
+#[derive(Debug, Clone)]
+pub struct SectionSchema {
+    pub type_name: &'static str,
+    pub properties: Vec<PropertySchema>,
+}
+
+/// Maps a section-type prefix (the part of `[type:id]` before the colon) to its schema
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    schemas: HashMap<&'static str, SectionSchema>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry { schemas: HashMap::new() }
+    }
+
+    /// Registers a schema, replacing any previously registered schema for the same type
+    pub fn register(&mut self, schema: SectionSchema) {
+        self.schemas.insert(schema.type_name, schema);
+    }
+
+    /// Looks up the schema for a section, matching on the part of its name before `:`
+    fn schema_for<'a>(&'a self, section_name: &str) -> Option<&'a SectionSchema> {
+        let type_name = section_name.split(':').next().unwrap_or(section_name);
+        self.schemas.get(type_name)
+    }
+}
+
+/// A single problem found while validating a `ConfigFile` against a `Registry`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// A property required by the schema is missing from the section
+    MissingRequired { section: String, key: &'static str },
+
+    /// A property's value doesn't match the kind the schema expects
+    TypeMismatch { section: String, key: String, expected: ValueKind },
+
+    /// An enum-typed property's value isn't one of the allowed variants
+    InvalidEnumValue { section: String, key: String, value: String },
+}
+
+/// Checks whether a single value matches the given `ValueKind`
+fn matches_kind(value: &[&str], kind: &ValueKind) -> bool {
+    match kind {
+        ValueKind::Bool => value.len() == 1 && matches!(value[0], "0" | "1"),
+        ValueKind::Int => value.len() == 1 && value[0].parse::<i64>().is_ok(),
+        ValueKind::Float => value.len() == 1 && value[0].parse::<f64>().is_ok(),
+        ValueKind::FloatList => !value.is_empty() && value.iter().all(|v| v.parse::<f64>().is_ok()),
+        ValueKind::Enum(variants) => value.len() == 1 && variants.contains(&value[0]),
+    }
+}
+
+impl<'a> ConfigFile<'a> {
+    /// Validates every section against its schema in `registry`
+    ///
+    /// Sections whose type has no registered schema are skipped. All errors are
+    /// collected rather than stopping at the first, so a malformed upstream repo
+    /// can be rejected with a complete report before anything is written into the
+    /// user's PrusaSlicer directory.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use crate::slicer::config::ConfigFile;
+    /// # use crate::slicer::schema::{PropertySchema, Registry, SectionSchema, ValidationError, ValueKind};
+    /// let mut registry = Registry::new();
+    /// registry.register(SectionSchema {
+    ///     type_name: "print",
+    ///     properties: vec![PropertySchema { key: "layer_height", required: true, kind: ValueKind::Float }],
+    /// });
+    ///
+    /// let file = ConfigFile::parse("[print:0.20mm]\nlayer_height = oops\n").unwrap();
+    ///
+    /// assert_eq!(
+    ///     file.validate(&registry),
+    ///     Err(vec![ValidationError::TypeMismatch {
+    ///         section: "print:0.20mm".to_string(),
+    ///         key: "layer_height".to_string(),
+    ///         expected: ValueKind::Float,
+    ///     }])
+    /// );
+    /// ```
+    pub fn validate(&self, registry: &Registry) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        for section in self.sections.iter() {
+            let Some(schema) = registry.schema_for(section.name) else {
+                continue;
+            };
+
+            for prop_schema in schema.properties.iter() {
+                let found = section.properties.iter().find(|p| p.key == prop_schema.key);
+
+                let Some(prop) = found else {
+                    if prop_schema.required {
+                        errors.push(ValidationError::MissingRequired {
+                            section: section.name.to_string(),
+                            key: prop_schema.key,
+                        });
+                    }
+                    continue;
+                };
+
+                let value = prop.value.clone().unwrap_or_default();
+
+                if !matches_kind(&value, &prop_schema.kind) {
+                    match &prop_schema.kind {
+                        ValueKind::Enum(_) => errors.push(ValidationError::InvalidEnumValue {
+                            section: section.name.to_string(),
+                            key: prop.key.to_string(),
+                            value: value.join(";"),
+                        }),
+                        kind => errors.push(ValidationError::TypeMismatch {
+                            section: section.name.to_string(),
+                            key: prop.key.to_string(),
+                            expected: kind.clone(),
+                        }),
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
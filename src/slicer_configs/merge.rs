@@ -0,0 +1,37 @@
+/// Reconciliation of re-imported or additional vendor bundles
+///
+/// Loading several bundles (or re-importing a newer one) shouldn't accumulate
+/// duplicate or orphaned printer entries. `merge_bundle` removes every entry
+/// belonging to the incoming bundle's vendor before re-inserting its current
+/// set, so a re-import fully replaces that vendor's contents instead of
+/// unioning nozzle/material lists forever.
+use crate::slicer::config::ConfigFile;
+
+use super::vendor::{load_vendor_bundle, vendor_name};
+use super::Printers;
+
+/// Merges a vendor bundle into `printers`, first dropping any existing entry
+/// whose vendor matches the incoming bundle
+///
+/// # Example
+/// ```rust
+/// # use crate::slicer_configs::{merge_bundle, Printers};
+/// let mut printers = Printers::new();
+/// let old_bundle = "[vendor]\nname = Prusa Research\n\n[printer_model:MK3]\nvariants = 0.4\n";
+/// merge_bundle(&mut printers, old_bundle).unwrap();
+///
+/// let new_bundle = "[vendor]\nname = Prusa Research\n\n[printer_model:MK4]\nvariants = 0.4\n";
+/// merge_bundle(&mut printers, new_bundle).unwrap();
+///
+/// // The re-import replaced the vendor's entries rather than adding to them
+/// assert!(!printers.contains_key("MK3"));
+/// assert!(printers.contains_key("MK4"));
+/// ```
+pub fn merge_bundle<'a>(printers: &mut Printers<'a>, contents: &'a str) -> Result<(), &'static str> {
+    let file = ConfigFile::parse(contents)?;
+    let vendor = vendor_name(&file)?;
+
+    printers.retain(|_, entry| entry.vendor != vendor);
+
+    load_vendor_bundle(contents, printers)
+}
@@ -0,0 +1,14 @@
+/// Domain logic for the wizard's printer/nozzle catalogue: populating it from
+/// PrusaSlicer vendor bundles, and writing the user's selection back out
+mod merge;
+mod technology;
+mod vendor;
+mod write;
+
+pub use merge::merge_bundle;
+pub use technology::{
+    add_printer_variant, resolve_model, set_renamed_from, PrinterEntry, PrinterTechnology, PrinterVariants, Printers,
+    SlaDisplay, Variant,
+};
+pub use vendor::load_vendor_bundle;
+pub use write::export;
@@ -0,0 +1,152 @@
+/// Printer capability model
+///
+/// The original printer map assumed every printer is distinguished by nozzle
+/// diameter, but SLA/resin printers have no nozzle: they're instead keyed by
+/// material and a fixed display geometry. `PrinterTechnology` tags which of
+/// the two a printer model is, and `PrinterVariants` stores the matching
+/// technology-specific data instead of a bare nozzle list. Each entry also
+/// remembers which vendor bundle it came from, so re-importing a vendor can
+/// fully replace its previous entries.
+use std::collections::HashMap;
+
+use crate::print_host::HostConfig;
+
+/// Which print process a printer model uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrinterTechnology {
+    Fff,
+    Sla,
+}
+
+/// Resin display geometry for an SLA printer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SlaDisplay {
+    /// Pixel resolution of the LCD/DLP panel, e.g. (2560, 1440)
+    pub resolution: (u32, u32),
+    pub width_mm: f32,
+    pub height_mm: f32,
+}
+
+/// The variants a printer model offers, specific to its technology
+#[derive(Debug, Clone)]
+pub enum PrinterVariants<'a> {
+    Fff { nozzles: Vec<&'a str> },
+    Sla { materials: Vec<&'a str>, display: SlaDisplay },
+}
+
+/// A printer model's catalogue entry: its variants, the vendor bundle that
+/// supplied it, where to upload to it over the network (if configured), and
+/// any former names it was known by in an older vendor bundle
+#[derive(Debug, Clone)]
+pub struct PrinterEntry<'a> {
+    pub vendor: &'a str,
+    pub variants: PrinterVariants<'a>,
+    pub host: Option<HostConfig>,
+    pub renamed_from: Vec<&'a str>,
+}
+
+/// A single new variant to fold into a printer model's entry
+pub enum Variant<'a> {
+    Nozzle(&'a str),
+    Material { material: &'a str, display: SlaDisplay },
+}
+
+/// Printer catalogue: model name to its catalogue entry
+pub type Printers<'a> = HashMap<&'a str, PrinterEntry<'a>>;
+
+/// Resolves `name` to its current canonical model key
+///
+/// Vendor catalogues rename printer models over time, so a user's existing
+/// selection (or an imported config) can reference a model ID that no longer
+/// exists under that name. If `name` isn't a model currently known directly,
+/// this falls back to checking every entry's `renamed_from` list, so the
+/// nozzle/material can fold into the renamed entry instead of creating a dead
+/// duplicate.
+///
+/// # Example
+/// ```rust
+/// # use crate::slicer_configs::{add_printer_variant, resolve_model, set_renamed_from, Printers, Variant};
+/// let mut printers = Printers::new();
+/// add_printer_variant(&mut printers, "MK4", "Prusa Research", Variant::Nozzle("0.4"));
+/// set_renamed_from(&mut printers, "MK4", vec!["MK3S+"]);
+///
+/// assert_eq!(resolve_model(&printers, "MK4"), Some("MK4"));
+/// assert_eq!(resolve_model(&printers, "MK3S+"), Some("MK4"));
+/// assert_eq!(resolve_model(&printers, "unknown"), None);
+/// ```
+pub fn resolve_model<'a>(printers: &Printers<'a>, name: &str) -> Option<&'a str> {
+    if let Some((&key, _)) = printers.get_key_value(name) {
+        return Some(key);
+    }
+
+    printers
+        .iter()
+        .find_map(|(&key, entry)| entry.renamed_from.contains(&name).then_some(key))
+}
+
+/// Records `aliases` as the former names of `model`, if it's a known entry
+pub fn set_renamed_from<'a>(printers: &mut Printers<'a>, model: &str, aliases: Vec<&'a str>) {
+    if let Some(entry) = printers.get_mut(model) {
+        entry.renamed_from = aliases;
+    }
+}
+
+/// Adds `variant` to `name`'s entry in `printers`, creating the entry with the
+/// matching technology if it doesn't exist yet, and recording `vendor` as its
+/// current source
+///
+/// This replaces the old technology-blind `has_printer_and_nozzle`: branching
+/// on `variant` means loading a resin printer can never silently create a
+/// bogus empty-nozzle entry. `name` is resolved through `resolve_model` first,
+/// so an unknown but renamed model folds into its current entry.
+///
+/// # Example
+/// ```rust
+/// # use crate::slicer_configs::{add_printer_variant, Printers, PrinterVariants, Variant};
+/// let mut printers = Printers::new();
+/// add_printer_variant(&mut printers, "MK3", "Prusa Research", Variant::Nozzle("0.4"));
+/// add_printer_variant(&mut printers, "MK3", "Prusa Research", Variant::Nozzle("0.6"));
+///
+/// match &printers["MK3"].variants {
+///     PrinterVariants::Fff { nozzles } => assert_eq!(nozzles, &["0.4", "0.6"]),
+///     _ => panic!("expected an FFF entry"),
+/// }
+/// ```
+pub fn add_printer_variant<'a>(printers: &mut Printers<'a>, name: &'a str, vendor: &'a str, variant: Variant<'a>) {
+    let name = resolve_model(printers, name).unwrap_or(name);
+
+    let entry = printers.entry(name).or_insert_with(|| PrinterEntry {
+        vendor,
+        variants: match &variant {
+            Variant::Nozzle(_) => PrinterVariants::Fff { nozzles: Vec::new() },
+            Variant::Material { .. } => PrinterVariants::Sla {
+                materials: Vec::new(),
+                display: SlaDisplay::default(),
+            },
+        },
+        host: None,
+        renamed_from: Vec::new(),
+    });
+
+    // Keep the vendor current in case this entry is being re-populated in place
+    entry.vendor = vendor;
+
+    match (&mut entry.variants, variant) {
+        (PrinterVariants::Fff { nozzles }, Variant::Nozzle(nozzle)) => {
+            if !nozzles.contains(&nozzle) {
+                nozzles.push(nozzle);
+            }
+        }
+
+        (PrinterVariants::Sla { materials, display }, Variant::Material { material, display: new_display }) => {
+            if !materials.contains(&material) {
+                materials.push(material);
+            }
+            *display = new_display;
+        }
+
+        // Mismatched technology/variant pairing: the caller is confused about
+        // what this printer model is, so leave the existing variants untouched
+        _ => {}
+    }
+}
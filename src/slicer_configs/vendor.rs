@@ -0,0 +1,108 @@
+/// Parsing of PrusaSlicer vendor configuration bundles
+///
+/// A vendor bundle is an INI file with a `[vendor]` section (`name`,
+/// `config_version`) and one `[printer_model:<ID>]` section per model it
+/// carries. An FFF model has a `variants = 0.4;0.6;0.25` key enumerating the
+/// nozzle diameters it supports; an SLA model instead has `materials` and a
+/// fixed display geometry (`display_width`/`display_height` in mm,
+/// `display_pixels_x`/`display_pixels_y`). Both also carry `family` and
+/// `bed_model`, and a `technology` key (`FFF`/`SLA`) selects which to read.
+/// A model may also carry `renamed_from = OldName;OlderName` listing IDs it
+/// was previously known by in earlier bundle versions.
+use crate::slicer::config::{ConfigFile, Section};
+
+use super::technology::{add_printer_variant, set_renamed_from, PrinterTechnology, PrinterVariants, SlaDisplay, Variant};
+use super::Printers;
+
+/// Reads a single `key` from `section` and splits it on `;`
+fn values<'a>(section: &Section<'a>, key: &str) -> Vec<&'a str> {
+    section
+        .properties
+        .iter()
+        .find(|p| p.key == key)
+        .and_then(|p| p.value.clone())
+        .unwrap_or_default()
+}
+
+/// Reads a single scalar `key` from `section`
+fn scalar<'a>(section: &Section<'a>, key: &str) -> Option<&'a str> {
+    values(section, key).first().copied()
+}
+
+fn technology_of(section: &Section) -> PrinterTechnology {
+    match scalar(section, "technology") {
+        Some(t) if t.eq_ignore_ascii_case("SLA") => PrinterTechnology::Sla,
+        _ => PrinterTechnology::Fff,
+    }
+}
+
+/// Reads the bundle's own `[vendor]` section `name`, which identifies every
+/// printer model the bundle supplies
+pub(super) fn vendor_name<'a>(file: &ConfigFile<'a>) -> Result<&'a str, &'static str> {
+    file.section("vendor", "")
+        .and_then(|s| scalar(s, "name"))
+        .ok_or("vendor bundle missing a [vendor] section with a name")
+}
+
+/// Parses a vendor bundle and feeds every variant it declares into `printers`,
+/// so a full vendor catalogue can be loaded from disk in one step
+///
+/// # Example
+/// ```rust
+/// # use crate::slicer_configs::{load_vendor_bundle, Printers, PrinterVariants};
+/// let bundle = "[vendor]\nname = Prusa Research\n\n[printer_model:MK3]\ntechnology = FFF\nvariants = 0.4;0.6\n";
+///
+/// let mut printers = Printers::new();
+/// load_vendor_bundle(bundle, &mut printers).unwrap();
+///
+/// match &printers["MK3"].variants {
+///     PrinterVariants::Fff { nozzles } => assert_eq!(nozzles, &["0.4", "0.6"]),
+///     _ => panic!("expected an FFF entry"),
+/// }
+/// ```
+pub fn load_vendor_bundle<'a>(
+    contents: &'a str,
+    printers: &mut Printers<'a>,
+) -> Result<(), &'static str> {
+    let file = ConfigFile::parse(contents)?;
+    let vendor = vendor_name(&file)?;
+
+    for section in file.sections_of_type("printer_model") {
+        let model = section.id;
+
+        match technology_of(section) {
+            PrinterTechnology::Fff => {
+                for nozzle in values(section, "variants") {
+                    add_printer_variant(printers, model, vendor, Variant::Nozzle(nozzle));
+                }
+            }
+
+            PrinterTechnology::Sla => {
+                let display = SlaDisplay {
+                    resolution: (
+                        scalar(section, "display_pixels_x").and_then(|v| v.parse().ok()).unwrap_or(0),
+                        scalar(section, "display_pixels_y").and_then(|v| v.parse().ok()).unwrap_or(0),
+                    ),
+                    width_mm: scalar(section, "display_width").and_then(|v| v.parse().ok()).unwrap_or(0.0),
+                    height_mm: scalar(section, "display_height").and_then(|v| v.parse().ok()).unwrap_or(0.0),
+                };
+
+                for material in values(section, "materials") {
+                    add_printer_variant(
+                        printers,
+                        model,
+                        vendor,
+                        Variant::Material { material, display: display.clone() },
+                    );
+                }
+            }
+        }
+
+        let renamed_from = values(section, "renamed_from");
+        if !renamed_from.is_empty() {
+            set_renamed_from(printers, model, renamed_from);
+        }
+    }
+
+    Ok(())
+}
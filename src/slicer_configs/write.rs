@@ -1,10 +1,76 @@
+/// Serializes a printer catalogue selection back into a PrusaSlicer-compatible `.ini`
+///
+/// Emits an `action = ...` header plus `key = value` lines, one enabled entry
+/// per selected (printer model, variant) combination, alongside a
+/// `compatible_printers_condition` tying filament/print presets to that
+/// variant. Entries are sorted by model then variant so repeated runs
+/// produce byte-identical output, which matters for diffing and version control.
+use super::technology::{add_printer_variant, PrinterVariants, Printers, Variant};
 
 /// Write ini file
-pub fn format_ini( out: &mut String, contents: Vec<(String, String)>) {
-
+pub fn format_ini(out: &mut String, contents: Vec<(String, String)>) {
     for (k, v) in contents {
         let formated = format!("{} = {}\n", k, v);
         out.push_str(formated.as_str());
     }
+}
+
+/// Builds the `compatible_printers_condition` expression tying a print or
+/// filament preset to a single printer model and nozzle diameter
+fn nozzle_condition(model: &str, nozzle: &str) -> String {
+    format!("printer_notes=~/{}/ and nozzle_diameter[0]=={}", model, nozzle)
+}
+
+/// Builds the equivalent condition for an SLA printer model and material
+fn material_condition(model: &str, material: &str) -> String {
+    format!("printer_notes=~/{}/ and filament_type=={}", model, material)
+}
+
+/// Serializes the selected (printer model, variant) combinations in `printers`
+/// into a PrusaSlicer-compatible `.ini` string
+///
+/// # Example
+/// ```rust
+/// # use crate::slicer_configs::{add_printer_variant, export, Printers, Variant};
+/// let mut printers = Printers::new();
+/// add_printer_variant(&mut printers, "MK3", "Prusa Research", Variant::Nozzle("0.4"));
+///
+/// let ini = export(&printers);
+/// assert_eq!(
+///     ini,
+///     "action = add\n\
+///      MK3:0.4 = 1\n\
+///      compatible_printers_condition:MK3:0.4 = printer_notes=~/MK3/ and nozzle_diameter[0]==0.4\n"
+/// );
+/// ```
+pub fn export(printers: &Printers) -> String {
+    let mut entries = Vec::new();
+
+    for (model, entry) in printers.iter() {
+        match &entry.variants {
+            PrinterVariants::Fff { nozzles } => {
+                for nozzle in nozzles.iter() {
+                    entries.push((model.to_string(), nozzle.to_string(), nozzle_condition(model, nozzle)));
+                }
+            }
+            PrinterVariants::Sla { materials, .. } => {
+                for material in materials.iter() {
+                    entries.push((model.to_string(), material.to_string(), material_condition(model, material)));
+                }
+            }
+        }
+    }
+
+    entries.sort();
+
+    let mut contents = vec![("action".to_string(), "add".to_string())];
+
+    for (model, variant, condition) in entries.iter() {
+        contents.push((format!("{}:{}", model, variant), "1".to_string()));
+        contents.push((format!("compatible_printers_condition:{}:{}", model, variant), condition.clone()));
+    }
 
+    let mut out = String::new();
+    format_ini(&mut out, contents);
+    out
 }
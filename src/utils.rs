@@ -1,6 +1,75 @@
 use simple_home_dir::home_dir;
 use std::path::PathBuf;
 
+/// The kind of PrusaSlicer-related config a file turns out to be, so the
+/// loader can pick the right parser instead of guessing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileType {
+    /// The app's own `PrusaSlicer.ini`, carrying `[presets]`/`[recent]` sections
+    AppConfig,
+
+    /// A vendor bundle: a `[vendor]` section plus one or more `[printer_model:*]` sections
+    VendorBundle,
+
+    /// A single preset exported from the slicer: flat `key = value` lines, no sections
+    ExportedConfig,
+
+    /// A config embedded in a G-code file header, as `; key = value` comment lines
+    GcodeEmbedded,
+}
+
+/// Classifies `contents` as one of the known PrusaSlicer config file shapes
+///
+/// Checked in order: a `[vendor]` section alongside `[printer_model:*]`
+/// sections means a vendor bundle; `[presets]`/`[recent]` sections mean the
+/// app config; otherwise, lines starting with `; ` and containing `=` mean a
+/// G-code-embedded config; anything else with `printer_settings_id` or
+/// `filament_settings_id` and no section headers is an exported single-preset
+/// config.
+///
+/// # Example
+/// ```rust
+/// # use crate::utils::{guess_config_file_type, ConfigFileType};
+/// let bundle = "[vendor]\nname = Prusa Research\n\n[printer_model:MK3]\nvariants = 0.4\n";
+/// assert_eq!(guess_config_file_type(bundle), Some(ConfigFileType::VendorBundle));
+///
+/// let exported = "printer_settings_id = MK3\nnozzle_diameter = 0.4\n";
+/// assert_eq!(guess_config_file_type(exported), Some(ConfigFileType::ExportedConfig));
+/// ```
+pub fn guess_config_file_type(contents: &str) -> Option<ConfigFileType> {
+    let has_vendor_section = contents.lines().any(|l| l.trim() == "[vendor]");
+    let has_printer_model = contents.lines().any(|l| l.trim_start().starts_with("[printer_model:"));
+
+    if has_vendor_section && has_printer_model {
+        return Some(ConfigFileType::VendorBundle);
+    }
+
+    let has_app_section = contents
+        .lines()
+        .any(|l| matches!(l.trim(), "[presets]" | "[recent]"));
+
+    if has_app_section {
+        return Some(ConfigFileType::AppConfig);
+    }
+
+    let has_gcode_header = contents
+        .lines()
+        .any(|l| l.trim_start().starts_with("; ") && l.contains('='));
+
+    if has_gcode_header {
+        return Some(ConfigFileType::GcodeEmbedded);
+    }
+
+    let has_section_header = contents.lines().any(|l| l.trim_start().starts_with('['));
+    let has_settings_id = contents.contains("printer_settings_id") || contents.contains("filament_settings_id");
+
+    if !has_section_header && has_settings_id {
+        return Some(ConfigFileType::ExportedConfig);
+    }
+
+    None
+}
+
 /// Get the PrusaSlicer config dir depending on the OS
 ///
 /// For macOS it is `~/.config/PrusaSlicer`